@@ -8,6 +8,10 @@ use wasm_bindgen::prelude::*;
 ///
 /// # Arguments
 /// * `input` - GBLN-formatted string
+/// * `reviver` - optional `(key, value) => newValue` callback, mirroring
+///   `JSON.parse`'s reviver. Called bottom-up over every converted node
+///   (arrays/objects first, then their parent); returning `undefined` drops
+///   the key/element, any other return value replaces the node.
 ///
 /// # Returns
 /// JavaScript object/array/primitive value
@@ -23,16 +27,58 @@ use wasm_bindgen::prelude::*;
 /// console.log(data.user.name); // 'Alice'
 /// ```
 #[wasm_bindgen]
-pub fn parse(input: &str) -> Result<JsValue, JsValue> {
+pub fn parse(input: &str, reviver: Option<js_sys::Function>) -> Result<JsValue, JsValue> {
     let value = gbln_parse(input).map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+    let js_value = value_to_js(&value)?;
 
-    value_to_js(&value)
+    match reviver {
+        Some(reviver) => apply_reviver(js_value, "", &reviver),
+        None => Ok(js_value),
+    }
+}
+
+/// Parse GBLN string to JavaScript value, reconstructing richer JS types at
+/// schema-marked paths instead of the plain object/array/string [`parse`] produces.
+///
+/// # Arguments
+/// * `input` - GBLN-formatted string
+/// * `schema` - JS object mapping dotted field paths (e.g. `"user.createdAt"`)
+///   to a marker: `"date"` reconstructs a `Date` from an ISO-8601 string,
+///   `"map"` reconstructs a `Map` from an object, `"bytes"` reconstructs a
+///   `Uint8Array` from an array of byte values. Paths not present in `schema`
+///   fall back to the plain conversion used by [`parse`].
+///
+/// # Returns
+/// JavaScript object/array/primitive value, with schema-marked nodes as `Date`/`Map`/`Uint8Array`
+///
+/// # Errors
+/// Throws JsValue error if parsing fails, a schema marker is unknown, or a
+/// marked node does not have the shape the marker expects
+///
+/// # Example (JavaScript)
+/// ```js
+/// import { parseWithSchema } from 'gbln';
+///
+/// const data = parseWithSchema(gbln, { 'user.createdAt': 'date' });
+/// console.log(data.user.createdAt instanceof Date); // true
+/// ```
+#[wasm_bindgen(js_name = parseWithSchema)]
+pub fn parse_with_schema_js(input: &str, schema: JsValue) -> Result<JsValue, JsValue> {
+    let value = gbln_parse(input).map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+    let schema = parse_schema(&schema)?;
+    value_to_js_with_schema(&value, "", &schema)
 }
 
 /// Serialise JavaScript value to GBLN string (compact format).
 ///
 /// # Arguments
 /// * `value` - JavaScript object/array/primitive
+/// * `replacer` - optional `(key, value) => newValue` callback, mirroring
+///   `JSON.stringify`'s replacer. Called top-down on every node, starting
+///   with the root under key `""`, before it is converted; returning
+///   `undefined` drops an object key and turns an array element into `null`
+///   (as `JSON.stringify` does), any other return value is converted in its
+///   place.
 ///
 /// # Returns
 /// Compact GBLN string
@@ -49,7 +95,11 @@ pub fn parse(input: &str) -> Result<JsValue, JsValue> {
 /// // Returns: "user{id<u32>(123)name<s64>(Alice)}"
 /// ```
 #[wasm_bindgen(js_name = toString)]
-pub fn to_string_js(value: JsValue) -> Result<String, JsValue> {
+pub fn to_string_js(value: JsValue, replacer: Option<js_sys::Function>) -> Result<String, JsValue> {
+    let value = match &replacer {
+        Some(replacer) => apply_replacer(value, "", replacer)?,
+        None => value,
+    };
     let gbln_value = js_to_value(value)?;
     Ok(to_string(&gbln_value))
 }
@@ -58,6 +108,7 @@ pub fn to_string_js(value: JsValue) -> Result<String, JsValue> {
 ///
 /// # Arguments
 /// * `value` - JavaScript object/array/primitive
+/// * `replacer` - optional `(key, value) => newValue` callback; see [`toString`]
 ///
 /// # Returns
 /// Pretty-printed GBLN string with newlines and indentation
@@ -70,15 +121,148 @@ pub fn to_string_js(value: JsValue) -> Result<String, JsValue> {
 /// const gbln = toStringPretty(data);
 /// ```
 #[wasm_bindgen(js_name = toStringPretty)]
-pub fn to_string_pretty_js(value: JsValue) -> Result<String, JsValue> {
+pub fn to_string_pretty_js(
+    value: JsValue,
+    replacer: Option<js_sys::Function>,
+) -> Result<String, JsValue> {
+    let value = match &replacer {
+        Some(replacer) => apply_replacer(value, "", replacer)?,
+        None => value,
+    };
     let gbln_value = js_to_value(value)?;
     Ok(to_string_pretty(&gbln_value))
 }
 
+/// Serialise JavaScript value to GBLN string, pinning the wire type per field.
+///
+/// # Arguments
+/// * `value` - JavaScript object/array/primitive
+/// * `schema` - JS object mapping dotted field paths (e.g. `"user.id"`) to a
+///   GBLN type name: `"u8"`, `"u16"`, `"u32"`, `"u64"`, `"i8"`, `"i16"`, `"i32"`,
+///   `"i64"`, `"f32"`, `"f64"`, `"s64"`, `"s256"`, `"s1024"`, `"bool"`. Paths not
+///   present in `schema` fall back to the auto-detection used by [`toString`].
+///
+/// # Returns
+/// Compact GBLN string with field types pinned by `schema` instead of auto-detected
+///
+/// # Errors
+/// Throws JsValue error if serialisation fails, a schema type name is unknown,
+/// or a value does not fit (or overflows) its declared type
+///
+/// # Example (JavaScript)
+/// ```js
+/// import { toStringWithSchema } from 'gbln';
+///
+/// const data = { user: { id: 123, balance: 42.5, name: 'Alice' } };
+/// const schema = { 'user.id': 'u32', 'user.balance': 'f32', 'user.name': 's256' };
+/// const gbln = toStringWithSchema(data, schema);
+/// ```
+#[wasm_bindgen(js_name = toStringWithSchema)]
+pub fn to_string_with_schema_js(value: JsValue, schema: JsValue) -> Result<String, JsValue> {
+    let schema = parse_schema(&schema)?;
+    let gbln_value = js_to_value_with_schema(value, "", &schema)?;
+    Ok(to_string(&gbln_value))
+}
+
+/// Convert a JSON string directly to a GBLN string, without a manual
+/// `JSON.parse` → `toString` round-trip through JS values.
+///
+/// # Arguments
+/// * `json_string` - JSON-formatted string
+///
+/// # Returns
+/// Compact GBLN string
+///
+/// # Errors
+/// Throws JsValue error if the input is not valid JSON or conversion fails
+///
+/// # Example (JavaScript)
+/// ```js
+/// import { fromJson } from 'gbln';
+///
+/// const gbln = fromJson('{"user":{"id":123,"name":"Alice"}}');
+/// ```
+#[wasm_bindgen(js_name = fromJson)]
+pub fn from_json_js(json_string: &str) -> Result<String, JsValue> {
+    let parsed = js_sys::JSON::parse(json_string)
+        .map_err(|e| JsValue::from_str(&format!("Invalid JSON: {:?}", e)))?;
+
+    let value = if is_type_annotated_json(&parsed)? {
+        let data = js_sys::Reflect::get(&parsed, &JsValue::from_str("data"))?;
+        let types = js_sys::Reflect::get(&parsed, &JsValue::from_str("types"))?;
+        let schema = parse_schema(&types)?;
+        js_to_value_with_schema(data, "", &schema)?
+    } else {
+        js_to_value(parsed)?
+    };
+
+    Ok(to_string(&value))
+}
+
+/// Convert a JavaScript value to a JSON string, without a manual
+/// `toString`/`parse` → `JSON.stringify` round-trip through GBLN.
+///
+/// # Arguments
+/// * `value` - JavaScript object/array/primitive
+/// * `options` - optional JS object; `{ preserveTypes: true }` wraps the
+///   output as `{ data, types }`, where `types` maps dotted field paths to
+///   the exact GBLN type each field round-tripped as (int widths, `s64`/
+///   `s256`/`s1024` string-length hints), so a later [`fromJson`] call
+///   reconstructs the original GBLN types instead of auto-detecting them.
+///   64-bit integers outside JS's safe integer range are encoded as decimal
+///   strings in `data`, since JSON has no integer type wide enough to hold them exactly.
+///
+/// # Returns
+/// JSON string
+///
+/// # Errors
+/// Throws JsValue error if conversion or JSON serialisation fails
+///
+/// # Example (JavaScript)
+/// ```js
+/// import { toJson } from 'gbln';
+///
+/// const json = toJson({ user: { id: 123, name: 'Alice' } }, { preserveTypes: true });
+/// ```
+#[wasm_bindgen(js_name = toJson)]
+pub fn to_json_js(value: JsValue, options: JsValue) -> Result<String, JsValue> {
+    let preserve_types = js_sys::Reflect::get(&options, &JsValue::from_str("preserveTypes"))
+        .map(|v| v.is_truthy())
+        .unwrap_or(false);
+
+    let gbln_value = js_to_value(value)?;
+    let data = value_to_json_value(&gbln_value)?;
+
+    let output = if preserve_types {
+        let types = js_sys::Object::new();
+        for (path, ty) in collect_type_annotations(&gbln_value, "") {
+            js_sys::Reflect::set(&types, &JsValue::from_str(&path), &JsValue::from_str(&ty))
+                .map_err(|e| {
+                    JsValue::from_str(&format!("Failed to record type annotation: {:?}", e))
+                })?;
+        }
+
+        let wrapper = js_sys::Object::new();
+        js_sys::Reflect::set(&wrapper, &JsValue::from_str("data"), &data)
+            .map_err(|e| JsValue::from_str(&format!("Failed to build JSON wrapper: {:?}", e)))?;
+        js_sys::Reflect::set(&wrapper, &JsValue::from_str("types"), &types)
+            .map_err(|e| JsValue::from_str(&format!("Failed to build JSON wrapper: {:?}", e)))?;
+        wrapper.into()
+    } else {
+        data
+    };
+
+    js_sys::JSON::stringify(&output)
+        .map_err(|e| JsValue::from_str(&format!("Failed to stringify JSON: {:?}", e)))?
+        .as_string()
+        .ok_or_else(|| JsValue::from_str("JSON.stringify did not return a string"))
+}
+
 /// Convert GBLN Value to JavaScript value.
 ///
 /// Recursively converts GBLN types to JavaScript equivalents:
-/// - I8-I64, U8-U64, F32-F64 → number
+/// - I8-I32, U8-U32, F32-F64 → number
+/// - I64, U64 → BigInt (preserves full 64-bit precision)
 /// - Str → string
 /// - Bool → boolean
 /// - Null → null
@@ -89,11 +273,11 @@ fn value_to_js(value: &Value) -> Result<JsValue, JsValue> {
         Value::I8(n) => Ok(JsValue::from(*n)),
         Value::I16(n) => Ok(JsValue::from(*n)),
         Value::I32(n) => Ok(JsValue::from(*n)),
-        Value::I64(n) => Ok(JsValue::from(*n as f64)), // i64 → f64 (JS limitation)
+        Value::I64(n) => Ok(js_sys::BigInt::from(*n).into()),
         Value::U8(n) => Ok(JsValue::from(*n)),
         Value::U16(n) => Ok(JsValue::from(*n)),
         Value::U32(n) => Ok(JsValue::from(*n)),
-        Value::U64(n) => Ok(JsValue::from(*n as f64)), // u64 → f64 (JS limitation)
+        Value::U64(n) => Ok(js_sys::BigInt::from(*n).into()),
         Value::F32(n) => Ok(JsValue::from(*n)),
         Value::F64(n) => Ok(JsValue::from(*n)),
         Value::Str(s) => Ok(JsValue::from_str(s)),
@@ -101,6 +285,7 @@ fn value_to_js(value: &Value) -> Result<JsValue, JsValue> {
         Value::Null => Ok(JsValue::NULL),
 
         Value::Object(map) => {
+            // `map` is an IndexMap, so insertion order is preserved here.
             let obj = js_sys::Object::new();
             for (key, val) in map {
                 let js_val = value_to_js(val)?;
@@ -125,12 +310,16 @@ fn value_to_js(value: &Value) -> Result<JsValue, JsValue> {
 /// Convert JavaScript value to GBLN Value.
 ///
 /// Auto-detects appropriate GBLN type based on JavaScript value:
+/// - bigint → I64/U64 (exact, no precision loss)
 /// - number (integer) → smallest fitting signed/unsigned int type
 /// - number (float) → f64
 /// - string → s64/s256/s1024 based on length
 /// - boolean → Bool
 /// - null/undefined → Null
-/// - object → Object
+/// - Date → Str (canonical ISO-8601)
+/// - typed array / ArrayBuffer / DataView → Array of U8 (raw bytes)
+/// - Map → Object (string-keyed, insertion order preserved)
+/// - object → Object (keys kept in declaration order via `Object::entries`)
 /// - array → Array
 fn js_to_value(js_val: JsValue) -> Result<Value, JsValue> {
     // Null or undefined
@@ -143,6 +332,11 @@ fn js_to_value(js_val: JsValue) -> Result<Value, JsValue> {
         return Ok(Value::Bool(b));
     }
 
+    // BigInt (checked before as_f64, which does not recognise bigints)
+    if js_val.js_typeof().as_string().as_deref() == Some("bigint") {
+        return bigint_to_value(&js_val);
+    }
+
     // Number
     if let Some(n) = js_val.as_f64() {
         // Check if it's an integer
@@ -198,11 +392,63 @@ fn js_to_value(js_val: JsValue) -> Result<Value, JsValue> {
         return Ok(Value::Array(values));
     }
 
+    // Date → canonical ISO-8601 string
+    if js_val.is_instance_of::<js_sys::Date>() {
+        let date = js_sys::Date::from(js_val);
+        return Ok(Value::Str(date.to_iso_string().as_string().ok_or_else(
+            || JsValue::from_str("Date did not stringify to ISO-8601"),
+        )?));
+    }
+
+    // Typed array / ArrayBuffer / DataView → raw bytes
+    if js_val.is_instance_of::<js_sys::ArrayBuffer>() {
+        let bytes = js_sys::Uint8Array::new(&js_val).to_vec();
+        return Ok(Value::Array(bytes.into_iter().map(Value::U8).collect()));
+    }
+    if js_sys::ArrayBuffer::is_view(&js_val) {
+        // A view (e.g. `new Uint8Array(buf, 8, 4)` or a `.subarray()`) only
+        // covers part of its backing buffer — read just its own byteOffset/
+        // byteLength window instead of the whole buffer.
+        let buffer = js_sys::Reflect::get(&js_val, &JsValue::from_str("buffer"))?;
+        let byte_offset = js_sys::Reflect::get(&js_val, &JsValue::from_str("byteOffset"))?
+            .as_f64()
+            .ok_or_else(|| JsValue::from_str("Typed array is missing byteOffset"))?
+            as u32;
+        let byte_length = js_sys::Reflect::get(&js_val, &JsValue::from_str("byteLength"))?
+            .as_f64()
+            .ok_or_else(|| JsValue::from_str("Typed array is missing byteLength"))?
+            as u32;
+        let bytes =
+            js_sys::Uint8Array::new_with_byte_offset_and_length(&buffer, byte_offset, byte_length)
+                .to_vec();
+        return Ok(Value::Array(bytes.into_iter().map(Value::U8).collect()));
+    }
+
+    // Map → object keyed by its entry keys, preserving insertion order; a
+    // non-string key is an error
+    if js_val.is_instance_of::<js_sys::Map>() {
+        let entries =
+            js_sys::try_iter(&js_val)?.ok_or_else(|| JsValue::from_str("Map is not iterable"))?;
+        let mut map = indexmap::IndexMap::new();
+
+        for entry in entries {
+            let entry = js_sys::Array::from(&entry?);
+            let key = entry
+                .get(0)
+                .as_string()
+                .ok_or_else(|| JsValue::from_str("Map key must be string"))?;
+            let val = js_to_value(entry.get(1))?;
+            map.insert(key, val);
+        }
+
+        return Ok(Value::Object(map));
+    }
+
     // Object
     if js_val.is_object() {
         let obj = js_sys::Object::from(js_val);
         let entries = js_sys::Object::entries(&obj);
-        let mut map = std::collections::HashMap::new();
+        let mut map = indexmap::IndexMap::new();
 
         for i in 0..entries.length() {
             let entry = js_sys::Array::from(&entries.get(i));
@@ -222,3 +468,533 @@ fn js_to_value(js_val: JsValue) -> Result<Value, JsValue> {
         js_val
     )))
 }
+
+/// Convert a JS `bigint` value into `Value::I64` or `Value::U64`, depending on sign,
+/// erroring if it exceeds the range of either.
+fn bigint_to_value(js_val: &JsValue) -> Result<Value, JsValue> {
+    let big = js_sys::BigInt::from(js_val.clone());
+    let text = big
+        .to_string(10)
+        .map_err(|e| JsValue::from_str(&format!("Failed to stringify BigInt: {:?}", e)))?
+        .as_string()
+        .ok_or_else(|| JsValue::from_str("BigInt did not stringify to a string"))?;
+
+    if text.starts_with('-') {
+        text.parse::<i64>()
+            .map(Value::I64)
+            .map_err(|_| JsValue::from_str("BigInt value out of i64 range"))
+    } else {
+        text.parse::<u64>()
+            .map(Value::U64)
+            .map_err(|_| JsValue::from_str("BigInt value out of u64 range"))
+    }
+}
+
+/// Read a `schema` JS object (dotted path → GBLN type name) into a lookup map.
+fn parse_schema(schema: &JsValue) -> Result<std::collections::HashMap<String, String>, JsValue> {
+    let mut map = std::collections::HashMap::new();
+
+    if schema.is_undefined() || schema.is_null() {
+        return Ok(map);
+    }
+
+    let obj = js_sys::Object::from(schema.clone());
+    let entries = js_sys::Object::entries(&obj);
+
+    for i in 0..entries.length() {
+        let entry = js_sys::Array::from(&entries.get(i));
+        let path = entry
+            .get(0)
+            .as_string()
+            .ok_or_else(|| JsValue::from_str("Schema key must be string"))?;
+        let ty = entry
+            .get(1)
+            .as_string()
+            .ok_or_else(|| JsValue::from_str("Schema type must be string"))?;
+        map.insert(path, ty);
+    }
+
+    Ok(map)
+}
+
+/// Convert a JavaScript value to a GBLN `Value`, honoring a schema-declared type at `path`.
+///
+/// `path` is the dotted field path built up while descending into nested objects
+/// (e.g. `"user.id"`); it is looked up in `schema` at each node. A node without a
+/// schema entry falls back to the auto-detection in [`js_to_value`].
+fn js_to_value_with_schema(
+    js_val: JsValue,
+    path: &str,
+    schema: &std::collections::HashMap<String, String>,
+) -> Result<Value, JsValue> {
+    // Array: elements share the path of their parent field, so this is checked
+    // before consulting `schema` for `path` itself — otherwise a schema entry
+    // for the array's own path would always short-circuit before per-element
+    // coercion ever runs.
+    if js_sys::Array::is_array(&js_val) {
+        let arr = js_sys::Array::from(&js_val);
+        let mut values = Vec::new();
+
+        for i in 0..arr.length() {
+            let value = js_to_value_with_schema(arr.get(i), path, schema)?;
+            values.push(value);
+        }
+
+        return Ok(Value::Array(values));
+    }
+
+    if let Some(ty) = schema.get(path) {
+        return coerce_to_schema_type(&js_val, ty, path);
+    }
+
+    // Date / typed array / ArrayBuffer / DataView / Map: these are objects but
+    // have no (or misleading) own-enumerable properties, so the generic
+    // `Object::entries()` branch below would silently lose their data. Hand
+    // them to the same auto-detection `js_to_value` uses, same as the
+    // fallback at the end of this function; schema entries for paths below
+    // such a node (e.g. the keys of a `Map`) are not applied.
+    if js_val.is_instance_of::<js_sys::Date>()
+        || js_val.is_instance_of::<js_sys::ArrayBuffer>()
+        || js_sys::ArrayBuffer::is_view(&js_val)
+        || js_val.is_instance_of::<js_sys::Map>()
+    {
+        return js_to_value(js_val);
+    }
+
+    // Object: descend with an extended path so nested fields can match the schema.
+    if js_val.is_object() && !js_val.is_null() {
+        let obj = js_sys::Object::from(js_val);
+        let entries = js_sys::Object::entries(&obj);
+        let mut map = indexmap::IndexMap::new();
+
+        for i in 0..entries.length() {
+            let entry = js_sys::Array::from(&entries.get(i));
+            let key = entry
+                .get(0)
+                .as_string()
+                .ok_or_else(|| JsValue::from_str("Object key must be string"))?;
+            let child_path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{path}.{key}")
+            };
+            let val = js_to_value_with_schema(entry.get(1), &child_path, schema)?;
+            map.insert(key, val);
+        }
+
+        return Ok(Value::Object(map));
+    }
+
+    js_to_value(js_val)
+}
+
+/// Convert a GBLN `Value` to a JavaScript value, reconstructing `Date`/`Map`/`Uint8Array`
+/// at paths the schema marks as `"date"`/`"map"`/`"bytes"`.
+///
+/// `path` is built up the same dotted way as in [`js_to_value_with_schema`]. The
+/// markers accepted here are not the GBLN type names `toStringWithSchema`
+/// accepts; any other marker is an error.
+fn value_to_js_with_schema(
+    value: &Value,
+    path: &str,
+    schema: &std::collections::HashMap<String, String>,
+) -> Result<JsValue, JsValue> {
+    if let Some(marker) = schema.get(path) {
+        return match marker.as_str() {
+            "date" => {
+                let Value::Str(s) = value else {
+                    return Err(JsValue::from_str(&format!(
+                        "Value at '{path}' is not a string (schema marker 'date')"
+                    )));
+                };
+                let date = js_sys::Date::new(&JsValue::from_str(s));
+                if date.get_time().is_nan() {
+                    return Err(JsValue::from_str(&format!(
+                        "Value at '{path}' is not a valid date"
+                    )));
+                }
+                Ok(date.into())
+            }
+            "bytes" => {
+                let Value::Array(items) = value else {
+                    return Err(JsValue::from_str(&format!(
+                        "Value at '{path}' is not an array (schema marker 'bytes')"
+                    )));
+                };
+                let bytes = js_sys::Uint8Array::new_with_length(items.len() as u32);
+                for (i, item) in items.iter().enumerate() {
+                    let Value::U8(b) = item else {
+                        return Err(JsValue::from_str(&format!(
+                            "Value at '{path}[{i}]' is not a byte (schema marker 'bytes')"
+                        )));
+                    };
+                    bytes.set_index(i as u32, *b);
+                }
+                Ok(bytes.into())
+            }
+            "map" => {
+                let Value::Object(fields) = value else {
+                    return Err(JsValue::from_str(&format!(
+                        "Value at '{path}' is not an object (schema marker 'map')"
+                    )));
+                };
+                let map = js_sys::Map::new();
+                for (key, val) in fields {
+                    let child_path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    let js_val = value_to_js_with_schema(val, &child_path, schema)?;
+                    map.set(&JsValue::from_str(key), &js_val);
+                }
+                Ok(map.into())
+            }
+            other => Err(JsValue::from_str(&format!(
+                "Unknown schema marker '{other}' at '{path}'"
+            ))),
+        };
+    }
+
+    match value {
+        Value::Object(fields) => {
+            let obj = js_sys::Object::new();
+            for (key, val) in fields {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                let js_val = value_to_js_with_schema(val, &child_path, schema)?;
+                js_sys::Reflect::set(&obj, &JsValue::from_str(key), &js_val).map_err(|e| {
+                    JsValue::from_str(&format!("Failed to set object property: {:?}", e))
+                })?;
+            }
+            Ok(obj.into())
+        }
+        Value::Array(items) => {
+            let arr = js_sys::Array::new();
+            for item in items {
+                arr.push(&value_to_js_with_schema(item, path, schema)?);
+            }
+            Ok(arr.into())
+        }
+        other => value_to_js(other),
+    }
+}
+
+/// Coerce a single JS value into the GBLN type named by a schema entry.
+fn coerce_to_schema_type(js_val: &JsValue, ty: &str, path: &str) -> Result<Value, JsValue> {
+    let overflow = || JsValue::from_str(&format!("Value at '{path}' overflows schema type '{ty}'"));
+    let not_a_number = || {
+        JsValue::from_str(&format!(
+            "Value at '{path}' is not a number (schema type '{ty}')"
+        ))
+    };
+
+    macro_rules! int_variant {
+        ($int:ty, $variant:ident) => {{
+            let n = js_val.as_f64().ok_or_else(not_a_number)?;
+            // `MAX + 1` is exact for every width: for u64/i64, `MAX as f64`
+            // already rounds up to 2^64/2^63, which is itself out of range.
+            if n.fract() != 0.0 || n < <$int>::MIN as f64 || n >= <$int>::MAX as f64 + 1.0 {
+                return Err(overflow());
+            }
+            Ok(Value::$variant(n as $int))
+        }};
+    }
+
+    match ty {
+        "u8" => int_variant!(u8, U8),
+        "u16" => int_variant!(u16, U16),
+        "u32" => int_variant!(u32, U32),
+        // u64/i64 accept bigint and decimal strings (exact) in addition to
+        // number (subject to f64 precision) — strings round-trip the
+        // quoted-big-integer encoding `toJson({ preserveTypes: true })` emits.
+        "u64" if js_val.js_typeof().as_string().as_deref() == Some("bigint") => {
+            match bigint_to_value(js_val)? {
+                Value::U64(n) => Ok(Value::U64(n)),
+                _ => Err(overflow()),
+            }
+        }
+        "u64" if js_val.as_string().is_some() => js_val
+            .as_string()
+            .unwrap()
+            .parse::<u64>()
+            .map(Value::U64)
+            .map_err(|_| overflow()),
+        "u64" => int_variant!(u64, U64),
+        "i8" => int_variant!(i8, I8),
+        "i16" => int_variant!(i16, I16),
+        "i32" => int_variant!(i32, I32),
+        "i64" if js_val.js_typeof().as_string().as_deref() == Some("bigint") => {
+            match bigint_to_value(js_val)? {
+                Value::U64(n) => i64::try_from(n).map(Value::I64).map_err(|_| overflow()),
+                other => Ok(other),
+            }
+        }
+        "i64" if js_val.as_string().is_some() => js_val
+            .as_string()
+            .unwrap()
+            .parse::<i64>()
+            .map(Value::I64)
+            .map_err(|_| overflow()),
+        "i64" => int_variant!(i64, I64),
+        "f32" => {
+            let n = js_val.as_f64().ok_or_else(not_a_number)?;
+            if n.is_finite() && n.abs() > f32::MAX as f64 {
+                return Err(overflow());
+            }
+            Ok(Value::F32(n as f32))
+        }
+        "f64" => Ok(Value::F64(js_val.as_f64().ok_or_else(not_a_number)?)),
+        "bool" => js_val
+            .as_bool()
+            .map(Value::Bool)
+            .ok_or_else(|| JsValue::from_str(&format!("Value at '{path}' is not a boolean"))),
+        "s64" | "s256" | "s1024" => {
+            let s = js_val
+                .as_string()
+                .ok_or_else(|| JsValue::from_str(&format!("Value at '{path}' is not a string")))?;
+            let max_len = match ty {
+                "s64" => 64,
+                "s256" => 256,
+                _ => 1024,
+            };
+            if s.len() > max_len {
+                return Err(overflow());
+            }
+            Ok(Value::Str(s))
+        }
+        other => Err(JsValue::from_str(&format!(
+            "Unknown schema type '{other}' at '{path}'"
+        ))),
+    }
+}
+
+/// Apply a `JSON.parse`-style reviver to an already-converted JS value tree.
+///
+/// Recurses depth-first so arrays/objects are revived before their parent,
+/// then calls `reviver(key, value)` on the current node; `undefined` drops
+/// the corresponding array element or object key.
+fn apply_reviver(
+    value: JsValue,
+    key: &str,
+    reviver: &js_sys::Function,
+) -> Result<JsValue, JsValue> {
+    if js_sys::Array::is_array(&value) {
+        let arr = js_sys::Array::from(&value);
+        for i in 0..arr.length() {
+            let revived = apply_reviver(arr.get(i), &i.to_string(), reviver)?;
+            if revived.is_undefined() {
+                js_sys::Reflect::delete_property(&arr, &JsValue::from(i))?;
+            } else {
+                arr.set(i, &revived);
+            }
+        }
+    } else if value.is_object() && !value.is_null() {
+        let obj = js_sys::Object::from(value.clone());
+        let keys = js_sys::Object::keys(&obj);
+        for i in 0..keys.length() {
+            let k = keys.get(i).as_string().unwrap();
+            let item = js_sys::Reflect::get(&obj, &JsValue::from_str(&k))?;
+            let revived = apply_reviver(item, &k, reviver)?;
+            if revived.is_undefined() {
+                js_sys::Reflect::delete_property(&obj, &JsValue::from_str(&k))?;
+            } else {
+                js_sys::Reflect::set(&obj, &JsValue::from_str(&k), &revived)?;
+            }
+        }
+    }
+
+    reviver
+        .call2(&JsValue::NULL, &JsValue::from_str(key), &value)
+        .map_err(|e| JsValue::from_str(&format!("Reviver threw: {:?}", e)))
+}
+
+/// Apply a `JSON.stringify`-style replacer before a JS value tree is converted.
+///
+/// Calls `replacer(key, value)` on the current node first (root under key
+/// `""`), then recurses top-down into the result's own enumerable properties;
+/// `undefined` drops the corresponding object key and becomes `null` for an
+/// array element. `Date`, `ArrayBuffer`, typed-array, `DataView` and `Map`
+/// results are passed on whole, for `js_to_value` to convert. Builds a
+/// fresh output tree rather than mutating `value` in place, so the caller's
+/// original object/array is left untouched — matching `JSON.stringify`, which
+/// never mutates the value it serializes.
+fn apply_replacer(
+    value: JsValue,
+    key: &str,
+    replacer: &js_sys::Function,
+) -> Result<JsValue, JsValue> {
+    let value = replacer
+        .call2(&JsValue::NULL, &JsValue::from_str(key), &value)
+        .map_err(|e| JsValue::from_str(&format!("Replacer threw: {:?}", e)))?;
+
+    // These have no (or misleading) own-enumerable keys; rebuilding them as
+    // plain objects would lose their data.
+    if value.is_instance_of::<js_sys::Date>()
+        || value.is_instance_of::<js_sys::ArrayBuffer>()
+        || js_sys::ArrayBuffer::is_view(&value)
+        || value.is_instance_of::<js_sys::Map>()
+    {
+        return Ok(value);
+    }
+
+    if js_sys::Array::is_array(&value) {
+        let arr = js_sys::Array::from(&value);
+        let out = js_sys::Array::new();
+        for i in 0..arr.length() {
+            let replaced = apply_replacer(arr.get(i), &i.to_string(), replacer)?;
+            out.push(&if replaced.is_undefined() {
+                JsValue::NULL
+            } else {
+                replaced
+            });
+        }
+        return Ok(out.into());
+    } else if value.is_object() && !value.is_null() {
+        let obj = js_sys::Object::from(value);
+        let keys = js_sys::Object::keys(&obj);
+        let out = js_sys::Object::new();
+        for i in 0..keys.length() {
+            let k = keys.get(i).as_string().unwrap();
+            let item = js_sys::Reflect::get(&obj, &JsValue::from_str(&k))?;
+            let replaced = apply_replacer(item, &k, replacer)?;
+            if !replaced.is_undefined() {
+                js_sys::Reflect::set(&out, &JsValue::from_str(&k), &replaced)?;
+            }
+        }
+        return Ok(out.into());
+    }
+
+    Ok(value)
+}
+
+/// Whether a parsed JSON value has the shape of the `{ data, types }` wrapper
+/// [`toJson`] emits with `preserveTypes: true`: a non-array object with both a
+/// `data` and a `types` key. Plain user data with both keys is treated as a
+/// wrapper as well.
+fn is_type_annotated_json(value: &JsValue) -> Result<bool, JsValue> {
+    if !value.is_object() || js_sys::Array::is_array(value) {
+        return Ok(false);
+    }
+
+    let obj = js_sys::Object::from(value.clone());
+    Ok(js_sys::Reflect::has(&obj, &JsValue::from_str("data"))?
+        && js_sys::Reflect::has(&obj, &JsValue::from_str("types"))?)
+}
+
+/// Convert a GBLN `Value` to a JSON-safe JS value.
+///
+/// Identical to [`value_to_js`] except 64-bit integers are never emitted as
+/// `bigint`, which `JSON.stringify` cannot serialise: values within JS's safe
+/// integer range become plain numbers, and larger ones become decimal
+/// strings (lossless, but require the `types` sidecar from
+/// `toJson({ preserveTypes: true })` to parse back into an exact integer).
+fn value_to_json_value(value: &Value) -> Result<JsValue, JsValue> {
+    const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+
+    match value {
+        Value::I64(n) if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(n) => {
+            Ok(JsValue::from(*n as f64))
+        }
+        Value::I64(n) => Ok(JsValue::from_str(&n.to_string())),
+        Value::U64(n) if *n <= MAX_SAFE_INTEGER as u64 => Ok(JsValue::from(*n as f64)),
+        Value::U64(n) => Ok(JsValue::from_str(&n.to_string())),
+
+        Value::Object(map) => {
+            let obj = js_sys::Object::new();
+            for (key, val) in map {
+                let js_val = value_to_json_value(val)?;
+                js_sys::Reflect::set(&obj, &JsValue::from_str(key), &js_val).map_err(|e| {
+                    JsValue::from_str(&format!("Failed to set object property: {:?}", e))
+                })?;
+            }
+            Ok(obj.into())
+        }
+        Value::Array(vec) => {
+            let arr = js_sys::Array::new();
+            for val in vec {
+                arr.push(&value_to_json_value(val)?);
+            }
+            Ok(arr.into())
+        }
+
+        other => value_to_js(other),
+    }
+}
+
+/// Record the exact GBLN type of every scalar in `value`, keyed by dotted
+/// field path, for the `types` sidecar `toJson({ preserveTypes: true })` emits.
+///
+/// Only types a round-trip through JSON would otherwise lose are recorded:
+/// `F64`/`Bool`/`Null` already match JSON's native types and are skipped.
+///
+/// Array elements share their parent field's path (matching the schema
+/// convention `js_to_value_with_schema`/`coerce_to_schema_type` use for
+/// `toStringWithSchema`/`fromJson`), so a path can only be annotated
+/// when every element in the array agrees on its type at that path —
+/// otherwise a later `fromJson` would coerce every element to whichever
+/// element's type happened to be recorded last. Disagreeing paths are
+/// dropped rather than annotated, falling back to auto-detection for that
+/// field on reconstruction.
+fn collect_type_annotations(
+    value: &Value,
+    path: &str,
+) -> std::collections::HashMap<String, String> {
+    let mut out = std::collections::HashMap::new();
+
+    let scalar_type = match value {
+        Value::I8(_) => Some("i8"),
+        Value::I16(_) => Some("i16"),
+        Value::I32(_) => Some("i32"),
+        Value::I64(_) => Some("i64"),
+        Value::U8(_) => Some("u8"),
+        Value::U16(_) => Some("u16"),
+        Value::U32(_) => Some("u32"),
+        Value::U64(_) => Some("u64"),
+        Value::F32(_) => Some("f32"),
+        Value::Str(s) => Some(if s.len() <= 64 {
+            "s64"
+        } else if s.len() <= 256 {
+            "s256"
+        } else {
+            "s1024"
+        }),
+        Value::F64(_) | Value::Bool(_) | Value::Null => None,
+        Value::Object(_) | Value::Array(_) => None,
+    };
+
+    if let Some(ty) = scalar_type {
+        out.insert(path.to_string(), ty.to_string());
+        return out;
+    }
+
+    match value {
+        Value::Object(map) => {
+            for (key, val) in map {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                out.extend(collect_type_annotations(val, &child_path));
+            }
+        }
+        Value::Array(items) => {
+            let mut per_element = items
+                .iter()
+                .map(|item| collect_type_annotations(item, path));
+            if let Some(mut merged) = per_element.next() {
+                for element_map in per_element {
+                    merged.retain(|k, v| element_map.get(k) == Some(v));
+                }
+                out.extend(merged);
+            }
+        }
+        _ => {}
+    }
+
+    out
+}